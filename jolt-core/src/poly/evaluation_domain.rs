@@ -0,0 +1,166 @@
+#![allow(dead_code)]
+use crate::poly::field::JoltField;
+
+/// A multiplicative subgroup of `F` of size `2^log_size`, used to convert
+/// between the coefficient and point-value representations of a [`UniPoly`]
+/// in `O(n log n)` via a number-theoretic transform (NTT), instead of the
+/// `O(n^2)`/`O(n^3)` Horner evaluation and Vandermonde interpolation paths.
+///
+/// [`UniPoly`]: crate::poly::unipoly::UniPoly
+#[derive(Debug, Clone)]
+pub struct EvaluationDomain<F: JoltField> {
+    size: usize,
+    log_size: u32,
+    /// Primitive `2^log_size`-th root of unity, the NTT's butterfly generator.
+    generator: F,
+    generator_inv: F,
+    size_inv: F,
+}
+
+impl<F: JoltField> EvaluationDomain<F> {
+    /// Builds the domain of the smallest power of two `>= size`, deriving its
+    /// generator from the field's `2`-adic root of unity. Returns `None` if
+    /// that power of two exceeds the field's two-adicity, i.e. no subgroup of
+    /// that size exists.
+    pub fn new(size: usize) -> Option<Self> {
+        let rounded = size.max(1).next_power_of_two();
+        let log_size = rounded.trailing_zeros();
+        if log_size > F::two_adicity() {
+            return None;
+        }
+
+        let shift = F::two_adicity() - log_size;
+        let generator = Self::pow_u64(F::two_adic_root_of_unity(), 1u64 << shift);
+        let generator_inv = generator.inverse().unwrap();
+        let size_inv = F::from_u64(rounded as u64).unwrap().inverse().unwrap();
+
+        Some(Self {
+            size: rounded,
+            log_size,
+            generator,
+            generator_inv,
+            size_inv,
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Forward transform: coefficients -> evaluations at the domain's roots of unity.
+    ///
+    /// `coeffs.len()` must be at most `self.size`; a longer input would have
+    /// its high-order terms silently dropped by the zero-pad below instead of
+    /// transformed, which is the wrong answer rather than a clean failure.
+    pub fn fft(&self, coeffs: &[F]) -> Vec<F> {
+        debug_assert!(
+            coeffs.len() <= self.size,
+            "coeffs longer than domain size {}: would silently truncate",
+            self.size
+        );
+        let mut a = coeffs.to_vec();
+        a.resize(self.size, F::zero());
+        Self::ntt_in_place(&mut a, self.generator);
+        a
+    }
+
+    /// Inverse transform: evaluations at the domain's roots of unity -> coefficients.
+    ///
+    /// `evals.len()` must be at most `self.size`, for the same reason as in [`Self::fft`].
+    pub fn ifft(&self, evals: &[F]) -> Vec<F> {
+        debug_assert!(
+            evals.len() <= self.size,
+            "evals longer than domain size {}: would silently truncate",
+            self.size
+        );
+        let mut a = evals.to_vec();
+        a.resize(self.size, F::zero());
+        Self::ntt_in_place(&mut a, self.generator_inv);
+        for x in a.iter_mut() {
+            *x *= self.size_inv;
+        }
+        a
+    }
+
+    /// Iterative Cooley-Tukey NTT, in place. `a.len()` must be `self.size`
+    /// (a power of two) and `root` a primitive `a.len()`-th root of unity.
+    fn ntt_in_place(a: &mut [F], root: F) {
+        let n = a.len();
+
+        // Bit-reversal permutation.
+        let mut j = 0usize;
+        for i in 1..n {
+            let mut bit = n >> 1;
+            while j & bit != 0 {
+                j ^= bit;
+                bit >>= 1;
+            }
+            j |= bit;
+            if i < j {
+                a.swap(i, j);
+            }
+        }
+
+        let mut len = 2;
+        while len <= n {
+            let angle = Self::pow_u64(root, (n / len) as u64);
+            let mut i = 0;
+            while i < n {
+                let mut w = F::one();
+                for k in 0..len / 2 {
+                    let u = a[i + k];
+                    let v = a[i + k + len / 2] * w;
+                    a[i + k] = u + v;
+                    a[i + k + len / 2] = u - v;
+                    w *= angle;
+                }
+                i += len;
+            }
+            len <<= 1;
+        }
+    }
+
+    fn pow_u64(base: F, mut exp: u64) -> F {
+        let mut result = F::one();
+        let mut b = base;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= b;
+            }
+            b *= b;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+
+    #[test]
+    fn test_fft_ifft_roundtrip() {
+        test_fft_ifft_roundtrip_helper::<Fr>()
+    }
+
+    fn test_fft_ifft_roundtrip_helper<F: JoltField>() {
+        let domain = EvaluationDomain::<F>::new(8).unwrap();
+        let coeffs: Vec<F> = (0..8).map(|i| F::from_u64(i as u64 + 1).unwrap()).collect();
+
+        let evals = domain.fft(&coeffs);
+        let recovered = domain.ifft(&evals);
+
+        assert_eq!(recovered, coeffs);
+    }
+
+    #[test]
+    fn test_new_rounds_up_to_power_of_two() {
+        test_new_rounds_up_to_power_of_two_helper::<Fr>()
+    }
+
+    fn test_new_rounds_up_to_power_of_two_helper<F: JoltField>() {
+        assert_eq!(EvaluationDomain::<F>::new(5).unwrap().size(), 8);
+        assert_eq!(EvaluationDomain::<F>::new(8).unwrap().size(), 8);
+    }
+}
@@ -0,0 +1,77 @@
+#![allow(dead_code)]
+use ark_ff::{FftField, Field, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Field trait implemented by every scalar field used across the prover and
+/// verifier. Bridges arkworks' `PrimeField`/`FftField` traits with the small
+/// set of helpers (`from_u64`, `inverse`, two-adicity accessors) the rest of
+/// the crate calls directly, so callers don't need `ark_ff` in scope.
+pub trait JoltField:
+    Sized
+    + Copy
+    + Clone
+    + Debug
+    + PartialEq
+    + Send
+    + Sync
+    + CanonicalSerialize
+    + CanonicalDeserialize
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + for<'a> Add<&'a Self, Output = Self>
+    + for<'a> Sub<&'a Self, Output = Self>
+    + for<'a> Mul<&'a Self, Output = Self>
+    + for<'a> AddAssign<&'a Self>
+    + for<'a> SubAssign<&'a Self>
+    + for<'a> MulAssign<&'a Self>
+    + std::iter::Sum
+    + 'static
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn from_u64(n: u64) -> Option<Self>;
+    fn inverse(&self) -> Option<Self>;
+
+    /// The largest `s` such that `2^s` divides `modulus - 1`, i.e. the
+    /// largest power-of-two order a multiplicative subgroup of this field
+    /// can have. Bounds the maximum size an `EvaluationDomain` can be built
+    /// for.
+    fn two_adicity() -> u32;
+
+    /// A primitive `2^Self::two_adicity()`-th root of unity, the generator
+    /// `EvaluationDomain` derives its per-size roots of unity from.
+    fn two_adic_root_of_unity() -> Self;
+}
+
+impl<F: PrimeField + FftField> JoltField for F {
+    fn zero() -> Self {
+        <Self as ark_ff::Zero>::zero()
+    }
+
+    fn one() -> Self {
+        <Self as ark_ff::One>::one()
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Field::inverse(self)
+    }
+
+    fn two_adicity() -> u32 {
+        <Self as FftField>::TWO_ADICITY
+    }
+
+    fn two_adic_root_of_unity() -> Self {
+        <Self as FftField>::TWO_ADIC_ROOT_OF_UNITY
+    }
+}
@@ -1,4 +1,5 @@
 #![allow(dead_code)]
+use crate::poly::evaluation_domain::EvaluationDomain;
 use crate::poly::field::JoltField;
 use crate::utils::gaussian_elimination::gaussian_elimination;
 use crate::utils::transcript::{AppendToTranscript, ProofTranscript};
@@ -18,6 +19,17 @@ pub struct CompressedUniPoly<F: JoltField> {
     coeffs_except_linear_term: Vec<F>,
 }
 
+/// A polynomial given by its values `evals[i]` at an arbitrary node set
+/// `nodes[i]`, evaluated in `O(n)` per query via the barycentric form
+/// without ever materializing its coefficients. Precomputes the barycentric
+/// weights `w_i = 1 / prod_{j != i} (x_i - x_j)` once at construction.
+#[derive(Debug, Clone)]
+pub struct BarycentricPoly<F> {
+    nodes: Vec<F>,
+    weights: Vec<F>,
+    evals: Vec<F>,
+}
+
 impl<F: JoltField> UniPoly<F> {
     #[allow(dead_code)]
     pub fn from_coeff(coeffs: Vec<F>) -> Self {
@@ -25,19 +37,92 @@ impl<F: JoltField> UniPoly<F> {
     }
 
     pub fn from_evals(evals: &[F]) -> Self {
+        let coeffs = match evals.len() {
+            3 => Self::interpolate_quadratic(evals),
+            4 => Self::interpolate_cubic(evals),
+            _ => Self::vandermonde_interpolation(evals),
+        };
+        UniPoly { coeffs }
+    }
+
+    /// Interpolates the polynomial through `evals[i]` at `nodes[i]`, for an
+    /// arbitrary node set rather than the fixed `0, 1, ..., n-1` that
+    /// [`Self::from_evals`] assumes. Useful for sumcheck variants that sample
+    /// round polynomials on a shifted or coset node set.
+    pub fn from_evals_at(nodes: &[F], evals: &[F]) -> Self {
         UniPoly {
-            coeffs: Self::vandermonde_interpolation(evals),
+            coeffs: Self::vandermonde_interpolation_at(nodes, evals),
         }
     }
 
+    /// Closed-form interpolation of `ax^2 + bx + c` through `evals = [e0, e1, e2]`
+    /// at nodes `0, 1, 2`, avoiding the Vandermonde/Gaussian-elimination path for
+    /// the degree-2 round polynomials that dominate sumcheck.
+    fn interpolate_quadratic(evals: &[F]) -> Vec<F> {
+        let (e0, e1, e2) = (evals[0], evals[1], evals[2]);
+        let inverse_two = Self::inverse_two();
+        let c = e0;
+        let a = inverse_two * (e2 - e1 - e1 + e0);
+        let b = e1 - c - a;
+        vec![c, b, a]
+    }
+
+    /// Closed-form interpolation of `ax^3 + bx^2 + cx + d` through
+    /// `evals = [e0, e1, e2, e3]` at nodes `0, 1, 2, 3`.
+    fn interpolate_cubic(evals: &[F]) -> Vec<F> {
+        let (e0, e1, e2, e3) = (evals[0], evals[1], evals[2], evals[3]);
+        let inverse_two = Self::inverse_two();
+        let inverse_six = Self::inverse_six();
+        let d = e0;
+        let a = inverse_six * (e3 - e2 - e2 - e2 + e1 + e1 + e1 - e0);
+        let b = inverse_two * (e0 + e2) - e1 - a - a - a;
+        let c = e1 - d - b - a;
+        vec![d, c, b, a]
+    }
+
+    /// Field inverse of 2, used by the small-degree interpolation fast paths.
+    fn inverse_two() -> F {
+        Self::cached_small_inverse(2)
+    }
+
+    /// Field inverse of 6, used by the cubic interpolation fast path.
+    fn inverse_six() -> F {
+        Self::cached_small_inverse(6)
+    }
+
+    /// Computes and caches `F::from_u64(n).inverse()`, so that
+    /// `interpolate_quadratic`/`interpolate_cubic` - on the hot path of every
+    /// sumcheck round - pay for the modular inversion once per field type
+    /// instead of on every call. Keyed on `TypeId::of::<F>()` since a plain
+    /// generic `static` can't depend on `F` (each monomorphization is a
+    /// distinct item, not a distinct static instance).
+    fn cached_small_inverse(n: u64) -> F {
+        type InverseCache =
+            std::sync::Mutex<std::collections::HashMap<(std::any::TypeId, u64), Box<dyn std::any::Any + Send>>>;
+        static CACHE: std::sync::OnceLock<InverseCache> = std::sync::OnceLock::new();
+
+        let cache = CACHE.get_or_init(|| std::sync::Mutex::new(std::collections::HashMap::new()));
+        let key = (std::any::TypeId::of::<F>(), n);
+        let mut cache = cache.lock().unwrap();
+        let inverse = cache
+            .entry(key)
+            .or_insert_with(|| Box::new(F::from_u64(n).unwrap().inverse().unwrap()));
+        *inverse.downcast_ref::<F>().unwrap()
+    }
+
     fn vandermonde_interpolation(evals: &[F]) -> Vec<F> {
         let n = evals.len();
         let xs: Vec<F> = (0..n).map(|x| F::from_u64(x as u64).unwrap()).collect();
+        Self::vandermonde_interpolation_at(&xs, evals)
+    }
+
+    fn vandermonde_interpolation_at(nodes: &[F], evals: &[F]) -> Vec<F> {
+        let n = nodes.len();
 
         let mut vandermonde: Vec<Vec<F>> = Vec::with_capacity(n);
         for i in 0..n {
             let mut row = Vec::with_capacity(n);
-            let x = xs[i];
+            let x = nodes[i];
             row.push(F::one());
             row.push(x);
             for j in 2..n {
@@ -52,6 +137,12 @@ impl<F: JoltField> UniPoly<F> {
 
     /// Divide self by another polynomial, and returns the
     /// quotient and remainder.
+    ///
+    /// Below [`Self::FAST_DIVISION_DEGREE_THRESHOLD`] this runs schoolbook long
+    /// division. Above it, the quotient is recovered via power-series inversion
+    /// of the reversed divisor (see [`Self::divide_with_q_and_r_fast`]), which
+    /// turns the inner loop into polynomial multiplications instead of per-term
+    /// subtraction.
     pub fn divide_with_q_and_r(&self, divisor: &Self) -> Option<(Self, Self)> {
         if self.is_zero() {
             Some((Self::zero(), Self::zero()))
@@ -59,29 +150,132 @@ impl<F: JoltField> UniPoly<F> {
             None
         } else if self.degree() < divisor.degree() {
             Some((Self::zero(), self.clone()))
+        } else if self.degree() - divisor.degree() >= Self::FAST_DIVISION_DEGREE_THRESHOLD {
+            Some(self.divide_with_q_and_r_fast(divisor))
         } else {
-            // Now we know that self.degree() >= divisor.degree();
-            let mut quotient = vec![F::zero(); self.degree() - divisor.degree() + 1];
-            let mut remainder: Self = self.clone();
-            // Can unwrap here because we know self is not zero.
-            let divisor_leading_inv = divisor.leading_coefficient().unwrap().inverse().unwrap();
-            while !remainder.is_zero() && remainder.degree() >= divisor.degree() {
-                let cur_q_coeff = *remainder.leading_coefficient().unwrap() * divisor_leading_inv;
-                let cur_q_degree = remainder.degree() - divisor.degree();
-                quotient[cur_q_degree] = cur_q_coeff;
-
-                for (i, div_coeff) in divisor.coeffs.iter().enumerate() {
-                    remainder.coeffs[cur_q_degree + i] -= &(cur_q_coeff * div_coeff);
-                }
-                while let Some(true) = remainder.coeffs.last().map(|c| c == &F::zero()) {
-                    remainder.coeffs.pop();
-                }
+            Some(self.divide_with_q_and_r_schoolbook(divisor))
+        }
+    }
+
+    /// Degree gap (`deg(self) - deg(divisor)`) above which `divide_with_q_and_r`
+    /// switches from schoolbook long division to the Newton-iteration fast path.
+    /// Small sumcheck round polynomials stay on the simple path, where the
+    /// setup cost of the fast path isn't worth it.
+    const FAST_DIVISION_DEGREE_THRESHOLD: usize = 64;
+
+    /// Result-degree threshold above which `impl Mul for UniPoly` switches
+    /// from schoolbook multiplication to the NTT-based [`Self::mul`].
+    const FAST_MULTIPLICATION_DEGREE_THRESHOLD: usize = 64;
+
+    fn divide_with_q_and_r_schoolbook(&self, divisor: &Self) -> (Self, Self) {
+        // Here we know that self.degree() >= divisor.degree().
+        let mut quotient = vec![F::zero(); self.degree() - divisor.degree() + 1];
+        let mut remainder: Self = self.clone();
+        // Can unwrap here because we know self is not zero.
+        let divisor_leading_inv = divisor.leading_coefficient().unwrap().inverse().unwrap();
+        while !remainder.is_zero() && remainder.degree() >= divisor.degree() {
+            let cur_q_coeff = *remainder.leading_coefficient().unwrap() * divisor_leading_inv;
+            let cur_q_degree = remainder.degree() - divisor.degree();
+            quotient[cur_q_degree] = cur_q_coeff;
+
+            for (i, div_coeff) in divisor.coeffs.iter().enumerate() {
+                remainder.coeffs[cur_q_degree + i] -= &(cur_q_coeff * div_coeff);
+            }
+            while let Some(true) = remainder.coeffs.last().map(|c| c == &F::zero()) {
+                remainder.coeffs.pop();
             }
-            Some((Self::from_coeff(quotient), remainder))
         }
+        (Self::from_coeff(quotient), remainder)
+    }
+
+    /// Fast division via Newton iteration on the reversed divisor.
+    ///
+    /// Writing `m = deg(self)`, `n = deg(divisor)`, and `rev(p)(x) = x^deg(p)*p(1/x)`,
+    /// the quotient satisfies `rev(q) = rev(self) * rev(divisor)^-1 mod x^(m-n+1)`.
+    /// We compute that inverse via Newton's method (quadratic convergence, doubling
+    /// the working precision each step) and recover `q` by reversing back. The
+    /// remainder then falls out as `self - q*divisor`.
+    fn divide_with_q_and_r_fast(&self, divisor: &Self) -> (Self, Self) {
+        let m = self.degree();
+        let n = divisor.degree();
+        let target_len = m - n + 1;
+
+        let rev_divisor_inv = divisor.reverse().inverse_mod_xk(target_len);
+        let rev_self_trunc = self.reverse().truncated(target_len);
+        let mut rev_quotient_coeffs = rev_self_trunc.mul_ntt(&rev_divisor_inv).coeffs;
+        rev_quotient_coeffs.resize(target_len, F::zero());
+        rev_quotient_coeffs.reverse();
+        let quotient = Self::from_coeff(rev_quotient_coeffs);
+
+        let mut remainder_coeffs = self.coeffs.clone();
+        let qb = quotient.mul_ntt(divisor);
+        for (i, coeff) in qb.coeffs.iter().enumerate() {
+            remainder_coeffs[i] -= coeff;
+        }
+        while let Some(true) = remainder_coeffs.last().map(|c| c == &F::zero()) {
+            remainder_coeffs.pop();
+        }
+        (quotient, Self::from_coeff(remainder_coeffs))
     }
 
-    fn is_zero(&self) -> bool {
+    /// Reverses coefficient order: for `p` of degree `d`, returns the polynomial
+    /// with coefficients of `rev(p)(x) = x^d * p(1/x)`.
+    fn reverse(&self) -> Self {
+        let mut coeffs = self.coeffs.clone();
+        coeffs.reverse();
+        Self::from_coeff(coeffs)
+    }
+
+    /// Truncates to the first `len` coefficients, i.e. reduces mod `x^len`.
+    fn truncated(&self, len: usize) -> Self {
+        let mut coeffs = self.coeffs.clone();
+        coeffs.truncate(len);
+        Self::from_coeff(coeffs)
+    }
+
+    /// Schoolbook polynomial multiplication. Used below
+    /// `FAST_MULTIPLICATION_DEGREE_THRESHOLD`, where the setup cost of
+    /// `mul_ntt`'s `EvaluationDomain` isn't worth it.
+    fn mul_naive(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero_with_len(1);
+        }
+        let mut coeffs = vec![F::zero(); self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in other.coeffs.iter().enumerate() {
+                coeffs[i + j] += *a * b;
+            }
+        }
+        Self::from_coeff(coeffs)
+    }
+
+    /// Computes `self^-1 mod x^k` via Newton iteration, doubling precision
+    /// (`g <- g*(2 - self*g) mod x^(2*precision)`) each round starting from the
+    /// inverse of the constant term. Requires `self`'s constant term to be nonzero.
+    fn inverse_mod_xk(&self, k: usize) -> Self {
+        let const_term = self.coeffs[0];
+        assert!(const_term != F::zero(), "inverse_mod_xk: zero constant term");
+
+        let mut g = vec![const_term.inverse().unwrap()];
+        let mut precision = 1;
+        while precision < k {
+            precision = (precision * 2).min(k);
+            let self_trunc = self.truncated(precision);
+            let g_poly = Self::from_coeff(g);
+            let mut two_minus_self_g = self_trunc.mul_ntt(&g_poly).truncated(precision).coeffs;
+            two_minus_self_g.resize(precision, F::zero());
+            for coeff in two_minus_self_g.iter_mut() {
+                *coeff = -*coeff;
+            }
+            two_minus_self_g[0] += F::from_u64(2).unwrap();
+            let two_minus_self_g = Self::from_coeff(two_minus_self_g);
+            g = g_poly.mul_ntt(&two_minus_self_g).truncated(precision).coeffs;
+            g.resize(precision, F::zero());
+        }
+        Self::from_coeff(g)
+    }
+
+    pub fn is_zero(&self) -> bool {
         self.coeffs.is_empty() || self.coeffs.iter().all(|c| c == &F::zero())
     }
 
@@ -93,6 +287,28 @@ impl<F: JoltField> UniPoly<F> {
         Self::from_coeff(Vec::new())
     }
 
+    /// The all-zero polynomial represented with exactly `len` coefficients,
+    /// i.e. `degree() == len - 1` rather than collapsing to the empty vector
+    /// `zero()` does. Useful as an accumulator when building up a polynomial
+    /// of known target length coefficient-by-coefficient.
+    pub fn zero_with_len(len: usize) -> Self {
+        Self::from_coeff(vec![F::zero(); len])
+    }
+
+    /// Drops trailing zero coefficients so `degree()` reflects the true
+    /// degree after coefficient-wise arithmetic may have cancelled the
+    /// leading term(s). Always leaves at least one coefficient behind (the
+    /// zero polynomial is `vec![F::zero()]`, degree 0) so `degree()` never
+    /// underflows on an all-zero result.
+    fn trim(&mut self) {
+        while self.coeffs.len() > 1 && self.coeffs.last() == Some(&F::zero()) {
+            self.coeffs.pop();
+        }
+        if self.coeffs.is_empty() {
+            self.coeffs.push(F::zero());
+        }
+    }
+
     pub fn degree(&self) -> usize {
         self.coeffs.len() - 1
     }
@@ -119,6 +335,58 @@ impl<F: JoltField> UniPoly<F> {
         eval
     }
 
+    /// Evaluates `self` at every point of `domain` via NTT, in `O(n log n)`
+    /// instead of repeated Horner evaluation.
+    pub fn coeff_to_evals(&self, domain: &EvaluationDomain<F>) -> Vec<F> {
+        domain.fft(&self.coeffs)
+    }
+
+    /// Interpolates the polynomial whose evaluations over `domain` are
+    /// `evals`, via inverse NTT. This is the `EvaluationDomain` counterpart
+    /// to [`Self::from_evals`], for when the node set is the domain's roots
+    /// of unity rather than `0..evals.len()`.
+    pub fn evals_from_coeffs(evals: &[F], domain: &EvaluationDomain<F>) -> Self {
+        let mut coeffs = domain.ifft(evals);
+        while let Some(true) = coeffs.last().map(|c| c == &F::zero()) {
+            coeffs.pop();
+        }
+        Self::from_coeff(coeffs)
+    }
+
+    /// Multiplies two polynomials via NTT: zero-pad both to the next power of
+    /// two at or above the product's coefficient count, forward-transform, multiply
+    /// pointwise, then inverse-transform.
+    ///
+    /// Named distinctly from the `Mul` operator impl below (rather than
+    /// overloading the same `mul` name) so that `.mul(...)` call sites can't
+    /// accidentally bypass `FAST_MULTIPLICATION_DEGREE_THRESHOLD` by binding
+    /// to this unconditional-NTT inherent method instead of the size-aware
+    /// `impl Mul for UniPoly` dispatcher — inherent methods always win that
+    /// resolution over trait methods of the same name.
+    pub fn mul_ntt(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero_with_len(1);
+        }
+        let result_len = self.coeffs.len() + other.coeffs.len() - 1;
+        let domain = EvaluationDomain::new(result_len)
+            .expect("field's two-adicity too small for this product's degree");
+
+        let self_evals = domain.fft(&self.coeffs);
+        let other_evals = domain.fft(&other.coeffs);
+        let product_evals: Vec<F> = self_evals
+            .iter()
+            .zip(other_evals.iter())
+            .map(|(a, b)| *a * b)
+            .collect();
+
+        let mut coeffs = domain.ifft(&product_evals);
+        coeffs.truncate(result_len);
+        while let Some(true) = coeffs.last().map(|c| c == &F::zero()) {
+            coeffs.pop();
+        }
+        Self::from_coeff(coeffs)
+    }
+
     pub fn compress(&self) -> CompressedUniPoly<F> {
         let coeffs_except_linear_term = [&self.coeffs[..1], &self.coeffs[2..]].concat();
         debug_assert_eq!(coeffs_except_linear_term.len() + 1, self.coeffs.len());
@@ -128,6 +396,135 @@ impl<F: JoltField> UniPoly<F> {
     }
 }
 
+impl<F: JoltField> std::ops::Add for UniPoly<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let len = self.coeffs.len().max(rhs.coeffs.len());
+        let mut coeffs = vec![F::zero(); len];
+        for (i, c) in self.coeffs.into_iter().enumerate() {
+            coeffs[i] = c;
+        }
+        for (i, c) in rhs.coeffs.into_iter().enumerate() {
+            coeffs[i] += c;
+        }
+        let mut result = Self::from_coeff(coeffs);
+        result.trim();
+        result
+    }
+}
+
+impl<F: JoltField> std::ops::Sub for UniPoly<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let len = self.coeffs.len().max(rhs.coeffs.len());
+        let mut coeffs = vec![F::zero(); len];
+        for (i, c) in self.coeffs.into_iter().enumerate() {
+            coeffs[i] = c;
+        }
+        for (i, c) in rhs.coeffs.into_iter().enumerate() {
+            coeffs[i] -= c;
+        }
+        let mut result = Self::from_coeff(coeffs);
+        result.trim();
+        result
+    }
+}
+
+impl<F: JoltField> std::ops::Neg for UniPoly<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let coeffs = self.coeffs.into_iter().map(|c| -c).collect();
+        Self::from_coeff(coeffs)
+    }
+}
+
+impl<F: JoltField> std::ops::Mul<F> for UniPoly<F> {
+    type Output = Self;
+
+    /// Scales every coefficient by `scalar`.
+    fn mul(self, scalar: F) -> Self {
+        let coeffs = self.coeffs.into_iter().map(|c| c * scalar).collect();
+        let mut result = Self::from_coeff(coeffs);
+        result.trim();
+        result
+    }
+}
+
+impl<F: JoltField> std::ops::MulAssign<F> for UniPoly<F> {
+    fn mul_assign(&mut self, scalar: F) {
+        for c in self.coeffs.iter_mut() {
+            *c *= scalar;
+        }
+        self.trim();
+    }
+}
+
+impl<F: JoltField> std::ops::Mul for UniPoly<F> {
+    type Output = Self;
+
+    /// Polynomial product. Schoolbook below [`Self::FAST_MULTIPLICATION_DEGREE_THRESHOLD`],
+    /// delegating to the NTT-based [`Self::mul_ntt`] above it.
+    fn mul(self, rhs: Self) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return Self::zero_with_len(1);
+        }
+        if self.degree() + rhs.degree() >= Self::FAST_MULTIPLICATION_DEGREE_THRESHOLD {
+            self.mul_ntt(&rhs)
+        } else {
+            self.mul_naive(&rhs)
+        }
+    }
+}
+
+impl<F: JoltField> BarycentricPoly<F> {
+    /// Precomputes barycentric weights for `nodes`, storing `evals` for later
+    /// queries. Panics if `nodes` and `evals` differ in length.
+    pub fn new(nodes: Vec<F>, evals: Vec<F>) -> Self {
+        assert_eq!(nodes.len(), evals.len());
+        let weights = Self::compute_weights(&nodes);
+        Self {
+            nodes,
+            weights,
+            evals,
+        }
+    }
+
+    fn compute_weights(nodes: &[F]) -> Vec<F> {
+        (0..nodes.len())
+            .map(|i| {
+                let mut denominator = F::one();
+                for (j, &node_j) in nodes.iter().enumerate() {
+                    if j != i {
+                        denominator *= nodes[i] - node_j;
+                    }
+                }
+                denominator.inverse().unwrap()
+            })
+            .collect()
+    }
+
+    /// Evaluates at `r` in `O(n)` via `(sum_i w_i*e_i/(r-x_i)) / (sum_i w_i/(r-x_i))`,
+    /// short-circuiting to the stored `evals[i]` when `r` lands exactly on `nodes[i]`
+    /// to avoid dividing by zero.
+    pub fn evaluate(&self, r: &F) -> F {
+        if let Some(i) = self.nodes.iter().position(|x_i| x_i == r) {
+            return self.evals[i];
+        }
+
+        let mut numerator = F::zero();
+        let mut denominator = F::zero();
+        for i in 0..self.nodes.len() {
+            let term = self.weights[i] * (*r - self.nodes[i]).inverse().unwrap();
+            numerator += term * self.evals[i];
+            denominator += term;
+        }
+        numerator * denominator.inverse().unwrap()
+    }
+}
+
 impl<F: JoltField> CompressedUniPoly<F> {
     // we require eval(0) + eval(1) = hint, so we can solve for the linear term as:
     // linear_term = hint - 2 * constant_term - deg2 term - deg3 term
@@ -223,4 +620,187 @@ mod tests {
         let e4 = F::from_u64(109u64).unwrap();
         assert_eq!(poly.evaluate(&F::from_u64(4u64).unwrap()), e4);
     }
+
+    #[test]
+    fn test_divide_with_q_and_r_fast_matches_schoolbook() {
+        test_divide_with_q_and_r_fast_matches_schoolbook_helper::<Fr>()
+    }
+
+    fn test_divide_with_q_and_r_fast_matches_schoolbook_helper<F: JoltField>() {
+        let dividend_coeffs: Vec<F> = (0..200)
+            .map(|i| F::from_u64((i * 7 + 3) as u64).unwrap())
+            .collect();
+        let divisor_coeffs: Vec<F> = (0..10)
+            .map(|i| F::from_u64((i * 3 + 1) as u64).unwrap())
+            .collect();
+        let dividend = UniPoly::from_coeff(dividend_coeffs);
+        let divisor = UniPoly::from_coeff(divisor_coeffs);
+
+        assert!(
+            dividend.degree() - divisor.degree() >= UniPoly::<F>::FAST_DIVISION_DEGREE_THRESHOLD
+        );
+
+        let (fast_q, fast_r) = dividend.divide_with_q_and_r_fast(&divisor);
+        let (slow_q, slow_r) = dividend.divide_with_q_and_r_schoolbook(&divisor);
+
+        assert_eq!(fast_q.coeffs, slow_q.coeffs);
+        assert_eq!(fast_r.coeffs, slow_r.coeffs);
+    }
+
+    #[test]
+    fn test_ntt_mul_matches_schoolbook() {
+        test_ntt_mul_matches_schoolbook_helper::<Fr>()
+    }
+
+    fn test_ntt_mul_matches_schoolbook_helper<F: JoltField>() {
+        // (x + 1) * (x^2 + 2x + 3) = x^3 + 3x^2 + 5x + 3
+        let a = UniPoly::from_coeff(vec![F::one(), F::one()]);
+        let b = UniPoly::from_coeff(vec![
+            F::from_u64(3u64).unwrap(),
+            F::from_u64(2u64).unwrap(),
+            F::one(),
+        ]);
+
+        let product = a.mul_ntt(&b);
+        let expected = vec![
+            F::from_u64(3u64).unwrap(),
+            F::from_u64(5u64).unwrap(),
+            F::from_u64(3u64).unwrap(),
+            F::one(),
+        ];
+        assert_eq!(product.coeffs, expected);
+        assert_eq!(product.coeffs, a.mul_naive(&b).coeffs);
+    }
+
+    #[test]
+    fn test_evals_from_coeffs_roundtrips_coeff_to_evals() {
+        test_evals_from_coeffs_roundtrips_coeff_to_evals_helper::<Fr>()
+    }
+
+    fn test_evals_from_coeffs_roundtrips_coeff_to_evals_helper<F: JoltField>() {
+        let poly = UniPoly::from_coeff(vec![
+            F::from_u64(5u64).unwrap(),
+            F::from_u64(2u64).unwrap(),
+            F::from_u64(7u64).unwrap(),
+        ]);
+        let domain = EvaluationDomain::new(poly.coeffs.len()).unwrap();
+
+        let evals = poly.coeff_to_evals(&domain);
+        let recovered = UniPoly::evals_from_coeffs(&evals, &domain);
+
+        assert_eq!(recovered.coeffs, poly.coeffs);
+    }
+
+    #[test]
+    fn test_arithmetic_operators() {
+        test_arithmetic_operators_helper::<Fr>()
+    }
+
+    fn test_arithmetic_operators_helper<F: JoltField>() {
+        // p = x + 1, q = x^2 + 2x + 3
+        let p = UniPoly::from_coeff(vec![F::one(), F::one()]);
+        let q = UniPoly::from_coeff(vec![
+            F::from_u64(3u64).unwrap(),
+            F::from_u64(2u64).unwrap(),
+            F::one(),
+        ]);
+
+        // (p + q) = x^2 + 3x + 4
+        let sum = p.clone() + q.clone();
+        assert_eq!(
+            sum.coeffs,
+            vec![
+                F::from_u64(4u64).unwrap(),
+                F::from_u64(3u64).unwrap(),
+                F::one(),
+            ]
+        );
+
+        // (q - p) = x^2 + x + 2, and degree drops back on cancellation.
+        let diff = q.clone() - p.clone();
+        assert_eq!(
+            diff.coeffs,
+            vec![F::from_u64(2u64).unwrap(), F::one(), F::one()]
+        );
+        let cancelled = p.clone() - p.clone();
+        assert!(cancelled.is_zero());
+        assert_eq!(cancelled.degree(), 0);
+
+        // -p = -x - 1
+        let neg = -p.clone();
+        assert_eq!(neg.coeffs, vec![-F::one(), -F::one()]);
+
+        // 2*p = 2x + 2
+        let scaled = p.clone() * F::from_u64(2u64).unwrap();
+        assert_eq!(scaled.coeffs, vec![F::from_u64(2u64).unwrap(); 2]);
+
+        let mut scaled_assign = p.clone();
+        scaled_assign *= F::from_u64(2u64).unwrap();
+        assert_eq!(scaled_assign.coeffs, scaled.coeffs);
+
+        // p * q via the schoolbook-dispatching Mul operator matches NTT mul.
+        let product = p.clone() * q.clone();
+        assert_eq!(product.coeffs, p.mul_ntt(&q).coeffs);
+
+        // Multiplying by the zero polynomial never leaves `degree()` unsafe
+        // to call, through any of the three multiplication entry points.
+        let zero = UniPoly::zero_with_len(1);
+        assert_eq!((p.clone() * zero.clone()).degree(), 0);
+        assert_eq!(p.mul_ntt(&zero).degree(), 0);
+        assert_eq!(p.mul_naive(&zero).degree(), 0);
+    }
+
+    #[test]
+    fn test_from_evals_at_arbitrary_nodes() {
+        test_from_evals_at_arbitrary_nodes_helper::<Fr>()
+    }
+
+    fn test_from_evals_at_arbitrary_nodes_helper<F: JoltField>() {
+        // polynomial is 2x^2 + 3x + 1, sampled at nodes 2, 5, 7 instead of 0, 1, 2.
+        let nodes = vec![
+            F::from_u64(2u64).unwrap(),
+            F::from_u64(5u64).unwrap(),
+            F::from_u64(7u64).unwrap(),
+        ];
+        let evals = vec![
+            F::from_u64(15u64).unwrap(),
+            F::from_u64(66u64).unwrap(),
+            F::from_u64(120u64).unwrap(),
+        ];
+        let poly = UniPoly::from_evals_at(&nodes, &evals);
+
+        assert_eq!(poly.coeffs[0], F::one());
+        assert_eq!(poly.coeffs[1], F::from_u64(3u64).unwrap());
+        assert_eq!(poly.coeffs[2], F::from_u64(2u64).unwrap());
+    }
+
+    #[test]
+    fn test_barycentric_poly_matches_coefficient_evaluation() {
+        test_barycentric_poly_matches_coefficient_evaluation_helper::<Fr>()
+    }
+
+    fn test_barycentric_poly_matches_coefficient_evaluation_helper<F: JoltField>() {
+        // polynomial is 2x^2 + 3x + 1, sampled at nodes 2, 5, 7.
+        let nodes = vec![
+            F::from_u64(2u64).unwrap(),
+            F::from_u64(5u64).unwrap(),
+            F::from_u64(7u64).unwrap(),
+        ];
+        let evals = vec![
+            F::from_u64(15u64).unwrap(),
+            F::from_u64(66u64).unwrap(),
+            F::from_u64(120u64).unwrap(),
+        ];
+        let poly = UniPoly::from_evals_at(&nodes, &evals);
+        let barycentric = BarycentricPoly::new(nodes.clone(), evals.clone());
+
+        // Exact-node short circuit.
+        for (node, eval) in nodes.iter().zip(evals.iter()) {
+            assert_eq!(barycentric.evaluate(node), *eval);
+        }
+
+        // Off-node evaluation matches the coefficient form.
+        let r = F::from_u64(10u64).unwrap();
+        assert_eq!(barycentric.evaluate(&r), poly.evaluate(&r));
+    }
 }